@@ -1,85 +1,584 @@
 #![deny(clippy::pedantic, clippy::nursery)]
 
-use std::time::Duration;
+use std::{fmt, sync::Arc, time::Duration};
 
 use tokio::{
-    sync::{mpsc, oneshot},
+    sync::{broadcast, mpsc, oneshot, watch, OwnedSemaphorePermit, Semaphore, TryAcquireError},
     time::sleep,
 };
 
+/// Capacity of the broadcast channel behind [`CautiousBackoff::subscribe`].
+///
+/// Subscribers that fall more than this many transitions behind observe
+/// [`broadcast::error::RecvError::Lagged`] rather than stalling the backoff
+/// task, since broadcast channels drop the slowest receivers instead of
+/// applying backpressure.
+const EVENTS_CAPACITY: usize = 16;
+
 #[derive(Clone)]
-pub struct CautiousBackoff(mpsc::UnboundedSender<oneshot::Sender<Permit>>);
+pub struct CautiousBackoff {
+    dispatch: Dispatch,
+    events: broadcast::Sender<IntervalChanged>,
+    /// `true` while the backoff task is accepting new waiters, ready to
+    /// mint a permit; `false` while it is mid-sleep on a penalty or
+    /// `time_between_permits`, and, in serial mode, while a minted permit's
+    /// [`Outcome`] is still outstanding. Checked by [`CautiousBackoff::try_wait`] so
+    /// it can fail fast instead of waiting out the sleep.
+    ready: watch::Receiver<bool>,
+    /// `true` once [`CautiousBackoff::shutdown`] has been called. A `watch`
+    /// persists its last value, so unlike a `Notify` it can't lose the
+    /// signal if the backoff task isn't parked on it at the moment
+    /// `shutdown()` runs.
+    shutdown: watch::Sender<bool>,
+    /// Never shared with the spawned task, which only learns whether
+    /// `max_concurrency` was set: `wait`/`try_wait` acquire a slot here themselves
+    /// before a waiter is ever enqueued, so `try_wait` can fail fast on
+    /// concurrency saturation instead of enqueueing and blocking on the
+    /// mint. `None` when `max_concurrency` was `None`, i.e. strictly serial.
+    concurrency: Option<Arc<Semaphore>>,
+}
+
+#[derive(Clone)]
+enum Dispatch {
+    Unbounded(mpsc::UnboundedSender<Waiter>),
+    Bounded(mpsc::Sender<Waiter>),
+}
+
+/// A queued request for a [`Permit`], carried through the dispatch channel
+/// together with the concurrency slot the caller already reserved (if any),
+/// so the backoff task never has to acquire one itself.
+struct Waiter {
+    respond_to: oneshot::Sender<Permit>,
+    concurrency_permit: Option<OwnedSemaphorePermit>,
+}
+
+/// The side-channels shared with the spawned `backoff` task, bundled up to
+/// keep its constructor functions' argument lists manageable.
+struct Signals {
+    events: broadcast::Sender<IntervalChanged>,
+    ready: watch::Sender<bool>,
+    shutdown: watch::Receiver<bool>,
+}
 
 impl CautiousBackoff {
+    /// `max_concurrency` controls how many [`Permit`]s may be outstanding at
+    /// once. With `None`, the backoff task stays strictly serial: it mints
+    /// one permit and waits for its [`Outcome`] before minting the next.
+    /// With `Some(n)`, up to `n` permits can be in flight concurrently,
+    /// bounded by an internal semaphore that [`CautiousBackoff::wait`] and
+    /// [`CautiousBackoff::try_wait`] reserve a slot from before a permit is
+    /// even requested; a [`Outcome::Fail`] still pauses new issuance for the
+    /// penalty sleep, but `time_between_permits` is not applied between
+    /// successes since the semaphore already bounds throughput.
     #[must_use]
-    pub fn new(initial_wait: Duration, max_wait: Duration, time_between_permits: Duration) -> Self {
+    pub fn new(
+        initial_wait: Duration,
+        max_wait: Duration,
+        time_between_permits: Duration,
+        max_concurrency: Option<usize>,
+    ) -> Self {
         let (sender, recv) = mpsc::unbounded_channel();
-        tokio::spawn(backoff(recv, initial_wait, max_wait, time_between_permits));
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        let (ready_tx, ready) = watch::channel(true);
+        let (shutdown, shutdown_rx) = watch::channel(false);
+        let concurrency = max_concurrency.map(|n| Arc::new(Semaphore::new(n)));
+        tokio::spawn(backoff(
+            Inbox::Unbounded(recv),
+            initial_wait,
+            max_wait,
+            time_between_permits,
+            Signals {
+                events: events.clone(),
+                ready: ready_tx,
+                shutdown: shutdown_rx,
+            },
+            max_concurrency,
+        ));
 
-        Self(sender)
+        Self {
+            dispatch: Dispatch::Unbounded(sender),
+            events,
+            ready,
+            shutdown,
+            concurrency,
+        }
+    }
+
+    /// Like [`CautiousBackoff::new`], but backs the waiter queue with a
+    /// bounded channel of `queue_depth` slots instead of an unbounded one,
+    /// so callers stop piling up in memory once the backoff task falls
+    /// behind during a penalty sleep.
+    ///
+    /// With this constructor, [`CautiousBackoff::wait`] holds the caller
+    /// until a slot frees up once the queue is full, and
+    /// [`CautiousBackoff::try_wait`] fails fast with
+    /// [`TryWaitError::Throttled`] instead.
+    ///
+    /// See [`CautiousBackoff::new`] for what `max_concurrency` does.
+    #[must_use]
+    pub fn with_capacity(
+        initial_wait: Duration,
+        max_wait: Duration,
+        time_between_permits: Duration,
+        queue_depth: usize,
+        max_concurrency: Option<usize>,
+    ) -> Self {
+        let (sender, recv) = mpsc::channel(queue_depth);
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        let (ready_tx, ready) = watch::channel(true);
+        let (shutdown, shutdown_rx) = watch::channel(false);
+        let concurrency = max_concurrency.map(|n| Arc::new(Semaphore::new(n)));
+        tokio::spawn(backoff(
+            Inbox::Bounded(recv),
+            initial_wait,
+            max_wait,
+            time_between_permits,
+            Signals {
+                events: events.clone(),
+                ready: ready_tx,
+                shutdown: shutdown_rx,
+            },
+            max_concurrency,
+        ));
+
+        Self {
+            dispatch: Dispatch::Bounded(sender),
+            events,
+            ready,
+            shutdown,
+            concurrency,
+        }
+    }
+
+    /// Terminates the backoff task, waking every caller currently blocked
+    /// in [`CautiousBackoff::wait`] with [`Closed`] instead of leaving them
+    /// stuck forever. This includes callers parked on a saturated
+    /// concurrency limit, not just ones queued for a permit.
+    ///
+    /// Any clone of this handle observes the same shutdown, since they all
+    /// share the same underlying task.
+    pub fn shutdown(&self) {
+        let _ = self.shutdown.send(true);
+
+        // Closing the semaphore (rather than just the `watch`) is what
+        // wakes a `wait()` parked in `acquire_owned()` on a saturated
+        // concurrency limit - the backoff task returning doesn't touch it.
+        if let Some(semaphore) = &self.concurrency {
+            semaphore.close();
+        }
     }
 
+    /// Subscribes to transitions of the internal retry interval: every
+    /// doubling after [`Outcome::Fail`], every reset to `initial_wait`
+    /// after [`Outcome::Success`], and every clamp to `max_wait`. An outcome
+    /// that leaves the interval unchanged (a success while already at
+    /// `initial_wait`, a failure while already at `max_wait`) is not
+    /// broadcast.
+    ///
+    /// Broadcast channels drop the slowest receivers rather than applying
+    /// backpressure to the backoff task, so a subscriber that falls behind
+    /// observes [`broadcast::error::RecvError::Lagged`] instead of every
+    /// transition. Monitoring consumers should treat that as "missed some
+    /// transitions", not as a fatal error.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<IntervalChanged> {
+        self.events.subscribe()
+    }
+
+    /// # Errors
+    ///
+    /// Returns [`Closed`] if the backoff task has terminated, e.g. via
+    /// [`CautiousBackoff::shutdown`].
+    #[must_use = "permits should be used to signal outcome of task"]
+    pub async fn wait(&self) -> Result<Permit, Closed> {
+        let concurrency_permit = match &self.concurrency {
+            Some(semaphore) => Some(
+                Arc::clone(semaphore)
+                    .acquire_owned()
+                    .await
+                    .map_err(|_| Closed)?,
+            ),
+            None => None,
+        };
+
+        let (respond_to, rx) = oneshot::channel();
+        let waiter = Waiter {
+            respond_to,
+            concurrency_permit,
+        };
+
+        match &self.dispatch {
+            Dispatch::Unbounded(sender) => {
+                sender.send(waiter).map_err(|_| Closed)?;
+            }
+            Dispatch::Bounded(sender) => {
+                sender.send(waiter).await.map_err(|_| Closed)?;
+            }
+        }
+
+        rx.await.map_err(|_| Closed)
+    }
+
+    /// Like [`CautiousBackoff::wait`], but never awaits a penalty sleep, a
+    /// full waiter queue, or a saturated concurrency limit: if the backoff
+    /// task is currently mid-sleep or (with `max_concurrency` of `None`)
+    /// waiting on an outstanding permit's outcome, (when constructed via
+    /// [`CautiousBackoff::with_capacity`]) the queue has no free slot, or
+    /// (when `max_concurrency` is `Some`) no concurrency slot is free right
+    /// now, this returns [`TryWaitError::Throttled`] immediately instead of
+    /// blocking the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryWaitError::Throttled`] if a permit can't be minted right
+    /// now, or [`TryWaitError::Closed`] if the backoff task has terminated.
     #[must_use = "permits should be used to signal outcome of task"]
-    pub async fn wait(&self) -> Permit {
-        let (tx, rx) = oneshot::channel();
+    pub async fn try_wait(&self) -> Result<Permit, TryWaitError> {
+        if !*self.ready.borrow() {
+            return Err(TryWaitError::Throttled);
+        }
+
+        let concurrency_permit = match &self.concurrency {
+            Some(semaphore) => match Arc::clone(semaphore).try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(TryAcquireError::NoPermits) => return Err(TryWaitError::Throttled),
+                Err(TryAcquireError::Closed) => return Err(TryWaitError::Closed),
+            },
+            None => None,
+        };
+
+        let (respond_to, rx) = oneshot::channel();
+        let waiter = Waiter {
+            respond_to,
+            concurrency_permit,
+        };
+
+        match &self.dispatch {
+            Dispatch::Unbounded(sender) => {
+                sender.send(waiter).map_err(|_| TryWaitError::Closed)?;
+            }
+            Dispatch::Bounded(sender) => sender.try_send(waiter).map_err(|err| match err {
+                mpsc::error::TrySendError::Full(_) => TryWaitError::Throttled,
+                mpsc::error::TrySendError::Closed(_) => TryWaitError::Closed,
+            })?,
+        }
+
+        rx.await.map_err(|_| TryWaitError::Closed)
+    }
+}
 
-        unsafe {
-            // SAFETY: This will only fail if backoff task terminates, which
-            // happens only when recv fails, aka all senders are dropped.
-            // Impossible since we have one!
-            self.0.send(tx).unwrap_unchecked();
+/// Returned by [`CautiousBackoff::try_wait`] when a [`Permit`] can't be
+/// handed out immediately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryWaitError {
+    /// The backoff task is mid-sleep on a penalty (or `time_between_permits`),
+    /// is serial and still waiting on an outstanding permit's outcome, the
+    /// waiter queue has no free slot, or the concurrency limit is saturated. Try again later, or fall back to [`CautiousBackoff::wait`].
+    Throttled,
+    /// The backoff task has terminated.
+    Closed,
+}
 
-            // SAFETY: We never drop the sender
-            rx.await.unwrap_unchecked()
+impl fmt::Display for TryWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Throttled => f.write_str("backoff task is not ready to mint a permit"),
+            Self::Closed => f.write_str("backoff task has terminated"),
         }
     }
 }
 
+impl std::error::Error for TryWaitError {}
+
+/// Returned by [`CautiousBackoff::wait`] when the backoff task has
+/// terminated, e.g. via [`CautiousBackoff::shutdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("backoff task has terminated")
+    }
+}
+
+impl std::error::Error for Closed {}
+
+enum Inbox {
+    Unbounded(mpsc::UnboundedReceiver<Waiter>),
+    Bounded(mpsc::Receiver<Waiter>),
+}
+
+impl Inbox {
+    async fn recv(&mut self) -> Option<Waiter> {
+        match self {
+            Self::Unbounded(recv) => recv.recv().await,
+            Self::Bounded(recv) => recv.recv().await,
+        }
+    }
+}
+
+/// Resolves once `shutdown` carries `true`, whether that happened before
+/// this call started or happens while it's awaited. Unlike awaiting a
+/// `Notify`, this can't miss a shutdown fired while the task was busy
+/// elsewhere: the `watch` remembers it.
+async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|&shut_down| shut_down).await;
+}
+
 async fn backoff(
-    mut recv: mpsc::UnboundedReceiver<oneshot::Sender<Permit>>,
+    recv: Inbox,
+    initial: Duration,
+    cap: Duration,
+    time_between_permits: Duration,
+    signals: Signals,
+    max_concurrency: Option<usize>,
+) {
+    match max_concurrency {
+        None => backoff_serial(recv, initial, cap, time_between_permits, signals).await,
+        Some(_) => backoff_concurrent(recv, initial, cap, signals).await,
+    }
+}
+
+async fn backoff_serial(
+    mut recv: Inbox,
     initial: Duration,
     cap: Duration,
     time_between_permits: Duration,
+    signals: Signals,
 ) {
+    let Signals {
+        events,
+        ready,
+        mut shutdown,
+    } = signals;
     let mut current_retry_interval = initial;
 
     loop {
-        let Some(tx) = recv.recv().await else {
-            return;
+        let waiter = tokio::select! {
+            biased;
+            () = wait_for_shutdown(&mut shutdown) => return,
+            maybe_waiter = recv.recv() => match maybe_waiter {
+                Some(waiter) => waiter,
+                None => return,
+            },
         };
 
         let (outcome_tx, outcome_rx) = oneshot::channel();
 
-        // SAFETY: We never drop the receiver
-        unsafe { tx.send(Permit(outcome_tx)).unwrap_unchecked() };
+        // The waiter may have dropped its receiver already, e.g. its
+        // `wait()` future was cancelled while it sat in the queue. Drop
+        // the minted permit and move on instead of waiting on an outcome
+        // no one will ever report.
+        if waiter
+            .respond_to
+            .send(Permit(outcome_tx, waiter.concurrency_permit))
+            .is_err()
+        {
+            continue;
+        }
+
+        // This task is strictly serial: no new permit can be minted until
+        // this one resolves. Without lowering `ready` here, `try_wait()`
+        // would enqueue behind it and block on the mint instead of failing
+        // fast with `Throttled`, same as it does during a penalty sleep.
+        let _ = ready.send(false);
+
+        // A caller can hold a `Permit` open indefinitely, so this await
+        // must itself be cancellable by `shutdown` rather than only the
+        // surrounding sleeps.
+        let outcome = tokio::select! {
+            biased;
+            () = wait_for_shutdown(&mut shutdown) => return,
+            outcome = outcome_rx => outcome,
+        };
+
+        match outcome {
+            Ok(Outcome::Fail) => {
+                let previous_retry_interval = current_retry_interval;
+                current_retry_interval *= 2;
+
+                let reason = if current_retry_interval > cap {
+                    current_retry_interval = cap;
+                    IntervalChangeReason::Capped
+                } else {
+                    IntervalChangeReason::Doubled
+                };
+
+                // Only a genuine transition is newsworthy: once pinned at
+                // `cap`, every further `Fail` would otherwise re-broadcast
+                // the same `Capped` value forever.
+                if current_retry_interval != previous_retry_interval {
+                    let _ = events.send(IntervalChanged {
+                        new_interval: current_retry_interval,
+                        reason,
+                    });
+                }
 
-        if let Ok(outcome) = outcome_rx.await {
-            match outcome {
-                Outcome::Fail => {
+                tokio::select! {
+                    biased;
+                    () = wait_for_shutdown(&mut shutdown) => return,
+                    () = sleep(current_retry_interval) => {}
+                }
+            }
+            Ok(Outcome::Success) => {
+                let previous_retry_interval = current_retry_interval;
+                current_retry_interval = initial;
+
+                // Steady-state successes keep the interval at `initial`;
+                // only broadcast a `Reset` when it actually changes.
+                if current_retry_interval != previous_retry_interval {
+                    let _ = events.send(IntervalChanged {
+                        new_interval: current_retry_interval,
+                        reason: IntervalChangeReason::Reset,
+                    });
+                }
+
+                tokio::select! {
+                    biased;
+                    () = wait_for_shutdown(&mut shutdown) => return,
+                    () = sleep(time_between_permits) => {}
+                }
+            }
+            Err(_) => {}
+        }
+
+        let _ = ready.send(true);
+    }
+}
+
+/// Like [`backoff_serial`], but doesn't wait for a caller's [`Outcome`]
+/// before minting the next permit. Concurrency is bounded by the semaphore
+/// [`CautiousBackoff::wait`] and [`CautiousBackoff::try_wait`] reserve a
+/// slot from before a waiter ever reaches this task, not by anything held
+/// here. A reported [`Outcome::Fail`] still grows `current_retry_interval`
+/// and pauses new issuance for the penalty sleep; permits already in flight
+/// are left to finish and report their own outcome.
+async fn backoff_concurrent(mut recv: Inbox, initial: Duration, cap: Duration, signals: Signals) {
+    let Signals {
+        events,
+        ready,
+        mut shutdown,
+    } = signals;
+    let mut current_retry_interval = initial;
+
+    // Fed by the tasks spawned below as each in-flight `Permit` is resolved;
+    // never closes, since this function always holds `report_tx` too.
+    let (report_tx, mut report_rx) = mpsc::unbounded_channel::<Outcome>();
+
+    loop {
+        tokio::select! {
+            biased;
+
+            () = wait_for_shutdown(&mut shutdown) => return,
+
+            Some(outcome) = report_rx.recv() => {
+                let previous_retry_interval = current_retry_interval;
+
+                if matches!(outcome, Outcome::Fail) {
                     current_retry_interval *= 2;
 
-                    if current_retry_interval > cap {
+                    let reason = if current_retry_interval > cap {
                         current_retry_interval = cap;
+                        IntervalChangeReason::Capped
+                    } else {
+                        IntervalChangeReason::Doubled
+                    };
+
+                    // Only a genuine transition is newsworthy: once pinned
+                    // at `cap`, every further `Fail` would otherwise
+                    // re-broadcast the same `Capped` value forever.
+                    if current_retry_interval != previous_retry_interval {
+                        let _ = events.send(IntervalChanged {
+                            new_interval: current_retry_interval,
+                            reason,
+                        });
                     }
 
-                    sleep(current_retry_interval).await;
-                }
-                Outcome::Success => {
+                    let _ = ready.send(false);
+                    tokio::select! {
+                        biased;
+                        () = wait_for_shutdown(&mut shutdown) => return,
+                        () = sleep(current_retry_interval) => {}
+                    }
+                    let _ = ready.send(true);
+                } else {
                     current_retry_interval = initial;
 
-                    sleep(time_between_permits).await;
+                    // Steady-state successes keep the interval at
+                    // `initial`; only broadcast a `Reset` when it actually
+                    // changes.
+                    if current_retry_interval != previous_retry_interval {
+                        let _ = events.send(IntervalChanged {
+                            new_interval: current_retry_interval,
+                            reason: IntervalChangeReason::Reset,
+                        });
+                    }
+                }
+            }
+
+            // Only mint a new permit while not mid-sleep: this is what
+            // pauses new issuance after a `Fail`, same as the serial path,
+            // just without blocking permits already in flight.
+            maybe_waiter = recv.recv(), if *ready.borrow() => {
+                let Some(waiter) = maybe_waiter else { return };
+
+                let (outcome_tx, outcome_rx) = oneshot::channel();
+
+                // The waiter may have dropped its receiver already, e.g. its
+                // `wait()`/`try_wait()` future was cancelled while it sat in
+                // the queue. Drop the minted permit (and the concurrency
+                // slot with it) and move on instead of spawning a watcher
+                // for an outcome no one will ever report.
+                if waiter
+                    .respond_to
+                    .send(Permit(outcome_tx, waiter.concurrency_permit))
+                    .is_err()
+                {
+                    continue;
                 }
+
+                let report_tx = report_tx.clone();
+                tokio::spawn(async move {
+                    if let Ok(outcome) = outcome_rx.await {
+                        let _ = report_tx.send(outcome);
+                    }
+                });
             }
         }
     }
 }
 
+/// Emitted on [`CautiousBackoff::subscribe`] whenever the internal retry
+/// interval changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalChanged {
+    pub new_interval: Duration,
+    pub reason: IntervalChangeReason,
+}
+
+/// Why the retry interval changed; see [`IntervalChanged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalChangeReason {
+    /// A [`Outcome::Fail`] doubled the interval.
+    Doubled,
+    /// A [`Outcome::Fail`] doubled the interval past `max_wait`, so it was
+    /// clamped back down to `max_wait`.
+    Capped,
+    /// A [`Outcome::Success`] reset the interval back to `initial_wait`.
+    Reset,
+}
+
 pub enum Outcome {
     Success,
     Fail,
 }
 
-pub struct Permit(oneshot::Sender<Outcome>);
+pub struct Permit(
+    oneshot::Sender<Outcome>,
+    // Never read: held only so the concurrency slot it represents is freed
+    // when this `Permit` is consumed by `success`/`fail` (or simply dropped).
+    #[allow(dead_code)] Option<OwnedSemaphorePermit>,
+);
 
 impl Permit {
     pub fn success(self) {
@@ -90,3 +589,170 @@ impl Permit {
         let _ = self.0.send(Outcome::Fail);
     }
 }
+
+#[cfg(test)]
+// These tests hold permits/handles across `await` points on purpose, to
+// pin down cross-task timing; that's exactly what this lint flags.
+#[allow(clippy::significant_drop_tightening)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::{CautiousBackoff, IntervalChangeReason, TryWaitError};
+
+    #[tokio::test]
+    async fn try_wait_fails_fast_while_a_serial_permit_is_outstanding() {
+        let backoff = CautiousBackoff::new(
+            Duration::from_millis(200),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            None,
+        );
+        let permit = backoff.wait().await.unwrap();
+
+        let result = timeout(Duration::from_millis(100), backoff.try_wait()).await;
+        let outcome = result.unwrap();
+        assert!(matches!(outcome, Err(TryWaitError::Throttled)));
+
+        permit.success();
+    }
+
+    #[tokio::test]
+    async fn try_wait_fails_fast_on_a_full_bounded_queue() {
+        let backoff = CautiousBackoff::with_capacity(
+            Duration::from_millis(200),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            1,
+            None,
+        );
+        // The serial task is busy holding this permit, so it won't drain the
+        // queue, letting a second, queued wait() fill the single slot.
+        let permit = backoff.wait().await.unwrap();
+        let queued = {
+            let backoff = backoff.clone();
+            tokio::spawn(async move { backoff.wait().await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let result = timeout(Duration::from_millis(100), backoff.try_wait()).await;
+        let outcome = result.unwrap();
+        assert!(matches!(outcome, Err(TryWaitError::Throttled)));
+
+        permit.success();
+        queued.abort();
+    }
+
+    #[tokio::test]
+    async fn try_wait_fails_fast_when_concurrency_is_saturated() {
+        let backoff = CautiousBackoff::new(
+            Duration::from_millis(20),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            Some(1),
+        );
+        let permit = backoff.wait().await.unwrap();
+
+        let result = timeout(Duration::from_millis(100), backoff.try_wait()).await;
+        let outcome = result.unwrap();
+        assert!(matches!(outcome, Err(TryWaitError::Throttled)));
+
+        permit.success();
+        let result = timeout(Duration::from_millis(100), backoff.try_wait()).await;
+        assert!(result.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn shutdown_wakes_a_wait_queued_in_a_bounded_channel() {
+        let backoff = CautiousBackoff::with_capacity(
+            Duration::from_millis(200),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            1,
+            None,
+        );
+        let _permit = backoff.wait().await.unwrap();
+
+        let blocked = {
+            let backoff = backoff.clone();
+            tokio::spawn(async move { backoff.wait().await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        backoff.shutdown();
+
+        let result = timeout(Duration::from_millis(200), blocked).await;
+        assert!(result.unwrap().unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn shutdown_wakes_a_wait_blocked_on_a_saturated_concurrency_limit() {
+        let backoff = CautiousBackoff::new(
+            Duration::from_millis(20),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            Some(1),
+        );
+        let permit = backoff.wait().await.unwrap();
+
+        let blocked = {
+            let backoff = backoff.clone();
+            tokio::spawn(async move { backoff.wait().await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        backoff.shutdown();
+
+        let result = timeout(Duration::from_millis(200), blocked).await;
+        assert!(result.unwrap().unwrap().is_err());
+
+        permit.success();
+    }
+
+    #[tokio::test]
+    async fn concurrent_mode_allows_more_than_one_outstanding_permit() {
+        let backoff = CautiousBackoff::new(
+            Duration::from_millis(20),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            Some(2),
+        );
+
+        let first = backoff.wait().await.unwrap();
+        // With one slot already taken and a second free, this must resolve
+        // without ever touching the penalty sleep or queueing behind the
+        // first permit.
+        let second = timeout(Duration::from_millis(100), backoff.wait())
+            .await
+            .expect("second permit should mint immediately")
+            .unwrap();
+
+        first.success();
+        second.success();
+    }
+
+    #[tokio::test]
+    async fn broadcasts_only_on_an_actual_interval_transition() {
+        let backoff = CautiousBackoff::new(
+            Duration::from_millis(5),
+            Duration::from_secs(1),
+            Duration::from_millis(5),
+            None,
+        );
+        let mut events = backoff.subscribe();
+
+        // Already at `initial_wait`, so repeated successes are a no-op.
+        for _ in 0..3 {
+            backoff.wait().await.unwrap().success();
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(events.try_recv().is_err(), "steady-state success should not broadcast");
+
+        // One Fail is a genuine transition (Doubled) and must broadcast.
+        backoff.wait().await.unwrap().fail();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let changed = events.try_recv().expect("a real transition should broadcast");
+        assert_eq!(changed.reason, IntervalChangeReason::Doubled);
+    }
+}